@@ -7,7 +7,7 @@ async fn main() {
     // Create mock I2C device
     let expectations = [
         Transaction::write(0x68, vec![0x07, 0x01]), // Enable charger
-        Transaction::write_read(0x68, vec![0x10], vec![0x12, 0x34]), // Read battery voltage
+        Transaction::write_read(0x68, vec![0x21], vec![0x32]), // Read battery state of charge
     ];
     let i2c = I2cMock::new(&expectations);
 
@@ -17,7 +17,7 @@ async fn main() {
     // Enable charger
     adp5360.enable_charger().await.unwrap();
 
-    // Read battery voltage
-    let voltage = adp5360.read_battery_voltage().await.unwrap();
-    println!("Battery voltage: {:#04x}", voltage);
+    // Read battery state of charge
+    let soc = adp5360.read_state_of_charge().await.unwrap();
+    println!("Battery state of charge: {}%", soc);
 }
@@ -22,8 +22,8 @@ extern crate bitflags;
 /// // Enable battery charging
 /// pmic.enable_charger().await.unwrap();
 ///
-/// // Read battery voltage
-/// let voltage = pmic.read_battery_voltage().await.unwrap();
+/// // Read the battery state of charge
+/// let soc = pmic.read_state_of_charge().await.unwrap();
 /// # }
 /// ```
 pub struct ADP5360<I2C> {
@@ -32,6 +32,38 @@ pub struct ADP5360<I2C> {
     value: [u8; 1],
 }
 
+/// Expected value of `ManufacturerModelId` (0x00) for a genuine ADP5360.
+const ADP5360_MANUFACTURER_MODEL_ID: u8 = 0x00;
+
+/// Errors returned by this driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E> {
+    /// An I2C bus error occurred.
+    I2C(E),
+    /// `probe`/`new_and_probe` read back a manufacturer/model ID that does
+    /// not match a genuine ADP5360.
+    UnexpectedDevice {
+        /// The manufacturer/model ID byte that was read back.
+        id: u8,
+    },
+    /// A configuration passed to the driver was invalid.
+    InvalidConfig,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Self::I2C(e)
+    }
+}
+
+/// Manufacturer/model identifier read from `ManufacturerModelId` (0x00).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManufacturerModel(pub u8);
+
+/// Silicon revision read from `SiliconRevision` (0x01).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Revision(pub u8);
+
 /// Enum representing the I²C registers of the ADP5360.
 pub enum Register {
     /// Manufacturer and Model ID.
@@ -140,6 +172,244 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Bitfield definitions for Battery SOC Accumulation Control Register (0x22)
+    pub struct FuelGaugeControl: u8 {
+        const EN_FG = 1 << 0;   // Enable VBAT measurement / SOC computation
+    }
+}
+
+bitflags! {
+    /// Interrupt sources shared by `InterruptEnable1`/`InterruptFlag1` (0x32/0x34)
+    /// and `InterruptEnable2`/`InterruptFlag2` (0x33/0x35).
+    ///
+    /// The low byte of this bitflags value maps onto register 1, the high
+    /// byte onto register 2.
+    pub struct Interrupts: u16 {
+        const VBAT_LOW         = 1 << 0;  // Battery SOC low
+        const VBAT_HIGH        = 1 << 1;  // Battery voltage high
+        const BATTERY_OVERVOLTAGE = 1 << 2;  // Battery overvoltage
+        const CHARGER_TIMER_EXPIRED = 1 << 3;  // Charger timer expired
+        const BATTERY_DETECTION = 1 << 4;  // Battery detection
+        const CHARGE_COMPLETE   = 1 << 5;  // Charge complete
+        const THERMISTOR_REGION_CHANGE = 1 << 6;  // Thermistor/JEITA region change
+        const VBUS_OVERVOLTAGE  = 1 << 7;  // VBUS overvoltage
+        const VBUS_CHANGE       = 1 << 8;  // VBUS present/removed
+        const WATCHDOG_TIMEOUT  = 1 << 9;  // Watchdog timer expired
+    }
+}
+
+/// Open-circuit-voltage fuel-gauge model for a specific battery cell.
+///
+/// The ten breakpoints are 8-bit voltage codes (`VoltageSoc0` through
+/// `VoltageSoc100`) that the ADP5360 interpolates between to map a measured
+/// battery voltage to one of the fixed SOC points 0/5/11/19/28/41/55/69/84/100 %,
+/// together with the battery capacity code used to scale the accumulation.
+///
+/// The breakpoints must be monotonically increasing (`voltage_soc_0` the
+/// lowest, `voltage_soc_100` the highest); see [`BatteryModel::is_monotonic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatteryModel {
+    /// OCV code for 0% SOC.
+    pub voltage_soc_0: u8,
+    /// OCV code for 5% SOC.
+    pub voltage_soc_5: u8,
+    /// OCV code for 11% SOC.
+    pub voltage_soc_11: u8,
+    /// OCV code for 19% SOC.
+    pub voltage_soc_19: u8,
+    /// OCV code for 28% SOC.
+    pub voltage_soc_28: u8,
+    /// OCV code for 41% SOC.
+    pub voltage_soc_41: u8,
+    /// OCV code for 55% SOC.
+    pub voltage_soc_55: u8,
+    /// OCV code for 69% SOC.
+    pub voltage_soc_69: u8,
+    /// OCV code for 84% SOC.
+    pub voltage_soc_84: u8,
+    /// OCV code for 100% SOC.
+    pub voltage_soc_100: u8,
+    /// Battery capacity code (`BatteryCapacity`, 0x20).
+    pub battery_capacity: u8,
+}
+
+impl BatteryModel {
+    /// Returns `true` if the OCV breakpoints are monotonically increasing
+    /// from `voltage_soc_0` to `voltage_soc_100`.
+    pub fn is_monotonic(&self) -> bool {
+        let points = [
+            self.voltage_soc_0,
+            self.voltage_soc_5,
+            self.voltage_soc_11,
+            self.voltage_soc_19,
+            self.voltage_soc_28,
+            self.voltage_soc_41,
+            self.voltage_soc_55,
+            self.voltage_soc_69,
+            self.voltage_soc_84,
+            self.voltage_soc_100,
+        ];
+        points.windows(2).all(|pair| pair[0] < pair[1])
+    }
+}
+
+/// Charger state machine, decoded from bits `[2:0]` of `ChargerStatus1` (0x08).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChargerState {
+    /// Charger is off.
+    Off,
+    /// Trickle charge.
+    TrickleCharge,
+    /// Fast charge, constant current phase.
+    FastChargeConstantCurrent,
+    /// Fast charge, constant voltage phase.
+    FastChargeConstantVoltage,
+    /// Charge complete.
+    ChargeComplete,
+    /// Charge timer expired.
+    TimerExpired,
+    /// Battery detection in progress.
+    BatteryDetection,
+    /// Charger suspended.
+    Suspend,
+}
+
+impl ChargerState {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0x07 {
+            0 => Self::Off,
+            1 => Self::TrickleCharge,
+            2 => Self::FastChargeConstantCurrent,
+            3 => Self::FastChargeConstantVoltage,
+            4 => Self::ChargeComplete,
+            5 => Self::TimerExpired,
+            6 => Self::BatteryDetection,
+            _ => Self::Suspend,
+        }
+    }
+}
+
+/// JEITA thermal region, decoded from bits `[6:4]` of `ChargerStatus2` (0x09).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalRegion {
+    /// Battery temperature is within the normal charging range.
+    Normal,
+    /// Battery temperature is cold (below the `Thermistor0CThreshold`).
+    Cold,
+    /// Battery temperature is cool (between the 0°C and 10°C thresholds).
+    Cool,
+    /// Battery temperature is warm (between the 45°C and 60°C thresholds).
+    Warm,
+    /// Battery temperature is hot (above the `Thermistor60CThreshold`).
+    Hot,
+}
+
+impl ThermalRegion {
+    fn from_bits(bits: u8) -> Self {
+        match (bits >> 4) & 0x07 {
+            1 => Self::Cold,
+            2 => Self::Cool,
+            3 => Self::Normal,
+            4 => Self::Warm,
+            5 => Self::Hot,
+            _ => Self::Normal,
+        }
+    }
+}
+
+bitflags! {
+    /// Bitfield definitions for Battery Protection Control Register (0x11)
+    pub struct BatteryProtectionControl: u8 {
+        const EN_UVLO = 1 << 0;   // Enable undervoltage lockout protection
+        const EN_OVCHG = 1 << 1;  // Enable overcharge current protection
+        const EN_OV = 1 << 2;     // Enable overvoltage protection
+        const HYST_UVLO = 1 << 3; // Undervoltage lockout hysteresis select
+    }
+}
+
+/// Static battery-protection limits programmed into `BatteryProtectionControl`
+/// (0x11) and the four associated threshold registers (0x12-0x15).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatteryProtection {
+    /// Enable/hysteresis control bits (`BatteryProtectionControl`, 0x11).
+    pub control: BatteryProtectionControl,
+    /// Under-voltage threshold code (`BatteryProtectionUndervoltageSetting`, 0x12).
+    pub undervoltage_threshold: u8,
+    /// Over-charge current threshold code (`BatteryProtectionOverchargeSetting`, 0x13).
+    pub overcharge_threshold: u8,
+    /// Over-voltage threshold code (`BatteryProtectionOvervoltageSetting`, 0x14).
+    pub overvoltage_threshold: u8,
+    /// Charge over-current threshold code (`BatteryProtectionChargeOverchargeSetting`, 0x15).
+    pub charge_overcharge_threshold: u8,
+}
+
+/// Wake-up source selected while in ship mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WakeSource {
+    /// Wake when VBUS is inserted.
+    VbusInsertion,
+    /// Wake on a button press.
+    Button,
+}
+
+/// Ship-mode configuration: selects what wakes the part back up out of the
+/// ultra-low-power ship state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShipModeConfig {
+    /// Wake-up source.
+    pub wake_source: WakeSource,
+}
+
+bitflags! {
+    /// Bitfield definitions for Ship Mode Register (0x36)
+    pub struct ShipModeControl: u8 {
+        const EN_SHIP_MODE = 1 << 0;     // Enter ship mode
+        const WAKE_SEL_BUTTON = 1 << 1;  // Wake on button press (clear to wake on VBUS insertion)
+    }
+}
+
+/// Decoded charger status, combining `ChargerStatus1` (0x08) and
+/// `ChargerStatus2` (0x09).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChargerStatus {
+    /// Charger state machine state.
+    pub state: ChargerState,
+    /// VBUS is present.
+    pub vbus_present: bool,
+    /// VBUS over-voltage condition detected.
+    pub vbus_overvoltage: bool,
+    /// Thermistor temperature region.
+    pub thermistor_status: ThermalRegion,
+    /// Battery over-voltage condition detected.
+    pub battery_overvoltage: bool,
+}
+
+bitflags! {
+    /// Bitfield definitions for Battery Thermistor Control Register (0x0A)
+    pub struct ThermistorControl: u8 {
+        const EN_THR = 1 << 0;     // Enable the NTC thermistor measurement
+        const EN_IBAT = 1 << 1;    // Enable the thermistor bias current
+    }
+}
+
+/// JEITA thermistor configuration: NTC enable/bias control plus the four
+/// temperature comparator thresholds (`Thermistor0CThreshold` through
+/// `Thermistor60CThreshold`, 0x0B-0x0E).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThermistorConfig {
+    /// NTC enable/bias control bits (`BatteryThermistorControl`, 0x0A).
+    pub control: ThermistorControl,
+    /// 0°C comparator threshold code.
+    pub threshold_0c: u8,
+    /// 10°C comparator threshold code.
+    pub threshold_10c: u8,
+    /// 45°C comparator threshold code.
+    pub threshold_45c: u8,
+    /// 60°C comparator threshold code.
+    pub threshold_60c: u8,
+}
+
 impl<I2C> ADP5360<I2C>
 where
     I2C: I2c,
@@ -199,24 +469,372 @@ where
     /// # Returns
     ///
     /// A Result indicating success or an I2C bus error
-    pub async fn enable_charger(&mut self) -> Result<(), I2C::Error> {
+    pub async fn enable_charger(&mut self) -> Result<(), Error<I2C::Error>> {
         self.write_register(
             Register::ChargerFunctionSetting,
             ChargerFunctionSetting::EN_CHG.bits(),
         )
         .await
+        .map_err(Error::I2C)
+    }
+
+    /// Programs the fuel-gauge OCV curve and battery capacity.
+    ///
+    /// Writes the ten `VoltageSoc0`..`VoltageSoc100` breakpoints followed by
+    /// `BatteryCapacity`, in register order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfig` if `model`'s breakpoints are not
+    /// monotonically increasing, since programming a non-monotonic curve
+    /// would silently produce a broken SOC mapping.
+    pub async fn configure_fuel_gauge(
+        &mut self,
+        model: &BatteryModel,
+    ) -> Result<(), Error<I2C::Error>> {
+        if !model.is_monotonic() {
+            return Err(Error::InvalidConfig);
+        }
+
+        self.write_register(Register::VoltageSoc0, model.voltage_soc_0)
+            .await?;
+        self.write_register(Register::VoltageSoc5, model.voltage_soc_5)
+            .await?;
+        self.write_register(Register::VoltageSoc11, model.voltage_soc_11)
+            .await?;
+        self.write_register(Register::VoltageSoc19, model.voltage_soc_19)
+            .await?;
+        self.write_register(Register::VoltageSoc28, model.voltage_soc_28)
+            .await?;
+        self.write_register(Register::VoltageSoc41, model.voltage_soc_41)
+            .await?;
+        self.write_register(Register::VoltageSoc55, model.voltage_soc_55)
+            .await?;
+        self.write_register(Register::VoltageSoc69, model.voltage_soc_69)
+            .await?;
+        self.write_register(Register::VoltageSoc84, model.voltage_soc_84)
+            .await?;
+        self.write_register(Register::VoltageSoc100, model.voltage_soc_100)
+            .await?;
+        self.write_register(Register::BatteryCapacity, model.battery_capacity)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Reads the battery state of charge, as a percentage (0-100).
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the state of charge in percent or an I2C bus error
+    pub async fn read_state_of_charge(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(Register::BatterySoc)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Enables the fuel gauge (VBAT measurement and SOC computation).
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn enable_fuel_gauge(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.write_register(
+            Register::BatterySocAccumulationControl,
+            FuelGaugeControl::EN_FG.bits(),
+        )
+        .await
+        .map_err(Error::I2C)
+    }
+
+    /// Reads and decodes the charger status from `ChargerStatus1`/`ChargerStatus2`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the decoded `ChargerStatus` or an I2C bus error
+    pub async fn read_charger_status(&mut self) -> Result<ChargerStatus, Error<I2C::Error>> {
+        let status1 = self.read_register(Register::ChargerStatus1).await?;
+        let status2 = self.read_register(Register::ChargerStatus2).await?;
+
+        Ok(ChargerStatus {
+            state: ChargerState::from_bits(status1),
+            vbus_present: status1 & (1 << 4) != 0,
+            vbus_overvoltage: status1 & (1 << 3) != 0,
+            thermistor_status: ThermalRegion::from_bits(status2),
+            battery_overvoltage: status2 & 1 != 0,
+        })
+    }
+
+    /// Enables the given interrupt sources.
+    ///
+    /// ORs `interrupts` into `InterruptEnable1`/`InterruptEnable2`, leaving
+    /// any already-enabled sources untouched.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn enable_interrupts(
+        &mut self,
+        interrupts: Interrupts,
+    ) -> Result<(), Error<I2C::Error>> {
+        let bits = interrupts.bits();
+
+        let enable1 = self.read_register(Register::InterruptEnable1).await?;
+        self.write_register(Register::InterruptEnable1, enable1 | bits as u8)
+            .await?;
+
+        let enable2 = self.read_register(Register::InterruptEnable2).await?;
+        self.write_register(Register::InterruptEnable2, enable2 | (bits >> 8) as u8)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Reads and clears the pending (latched) interrupts.
+    ///
+    /// `InterruptFlag1`/`InterruptFlag2` are write-1-to-clear, so this reads
+    /// both bytes and then writes the same bits back to clear them.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the `Interrupts` that were pending or an I2C bus error
+    pub async fn take_pending_interrupts(&mut self) -> Result<Interrupts, Error<I2C::Error>> {
+        let flag1 = self.read_register(Register::InterruptFlag1).await?;
+        let flag2 = self.read_register(Register::InterruptFlag2).await?;
+
+        if flag1 != 0 {
+            self.write_register(Register::InterruptFlag1, flag1).await?;
+        }
+        if flag2 != 0 {
+            self.write_register(Register::InterruptFlag2, flag2).await?;
+        }
+
+        let bits = (flag1 as u16) | ((flag2 as u16) << 8);
+        Ok(Interrupts::from_bits_truncate(bits))
+    }
+
+    /// Programs the battery-protection limits (UV/OV/overcharge).
+    ///
+    /// Writes `BatteryProtectionControl` and the four threshold registers,
+    /// in register order.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn configure_battery_protection(
+        &mut self,
+        protection: &BatteryProtection,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_register(
+            Register::BatteryProtectionControl,
+            protection.control.bits(),
+        )
+        .await?;
+        self.write_register(
+            Register::BatteryProtectionUndervoltageSetting,
+            protection.undervoltage_threshold,
+        )
+        .await?;
+        self.write_register(
+            Register::BatteryProtectionOverchargeSetting,
+            protection.overcharge_threshold,
+        )
+        .await?;
+        self.write_register(
+            Register::BatteryProtectionOvervoltageSetting,
+            protection.overvoltage_threshold,
+        )
+        .await?;
+        self.write_register(
+            Register::BatteryProtectionChargeOverchargeSetting,
+            protection.charge_overcharge_threshold,
+        )
+        .await
+        .map_err(Error::I2C)
+    }
+
+    /// Reads back the currently programmed battery-protection limits.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the `BatteryProtection` configuration or an I2C bus error
+    pub async fn read_battery_protection(
+        &mut self,
+    ) -> Result<BatteryProtection, Error<I2C::Error>> {
+        let control = self
+            .read_register(Register::BatteryProtectionControl)
+            .await?;
+        let undervoltage_threshold = self
+            .read_register(Register::BatteryProtectionUndervoltageSetting)
+            .await?;
+        let overcharge_threshold = self
+            .read_register(Register::BatteryProtectionOverchargeSetting)
+            .await?;
+        let overvoltage_threshold = self
+            .read_register(Register::BatteryProtectionOvervoltageSetting)
+            .await?;
+        let charge_overcharge_threshold = self
+            .read_register(Register::BatteryProtectionChargeOverchargeSetting)
+            .await?;
+
+        Ok(BatteryProtection {
+            control: BatteryProtectionControl::from_bits_truncate(control),
+            undervoltage_threshold,
+            overcharge_threshold,
+            overvoltage_threshold,
+            charge_overcharge_threshold,
+        })
+    }
+
+    /// Programs the JEITA thermistor configuration.
+    ///
+    /// Writes `BatteryThermistorControl` followed by the four temperature
+    /// thresholds, in register order. Each write is its own single-byte I2C
+    /// transaction, so this ordering is a style convention shared with the
+    /// other `configure_*` methods, not a hardware requirement.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn configure_thermistor(
+        &mut self,
+        config: ThermistorConfig,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_register(Register::BatteryThermistorControl, config.control.bits())
+            .await?;
+        self.write_register(Register::Thermistor60CThreshold, config.threshold_60c)
+            .await?;
+        self.write_register(Register::Thermistor45CThreshold, config.threshold_45c)
+            .await?;
+        self.write_register(Register::Thermistor10CThreshold, config.threshold_10c)
+            .await?;
+        self.write_register(Register::Thermistor0CThreshold, config.threshold_0c)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Reads the current JEITA thermal region from `ChargerStatus2`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the `ThermalRegion` or an I2C bus error
+    pub async fn read_battery_temperature_region(
+        &mut self,
+    ) -> Result<ThermalRegion, Error<I2C::Error>> {
+        let status2 = self.read_register(Register::ChargerStatus2).await?;
+        Ok(ThermalRegion::from_bits(status2))
+    }
+
+    /// Enters ship mode, disconnecting the battery via the ISOFET for
+    /// storage/shipping.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn enter_ship_mode(
+        &mut self,
+        config: ShipModeConfig,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut bits = ShipModeControl::EN_SHIP_MODE;
+        if config.wake_source == WakeSource::Button {
+            bits |= ShipModeControl::WAKE_SEL_BUTTON;
+        }
+        self.write_register(Register::ShipMode, bits.bits())
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Toggles the `OFF_ISOFET` bit in `ChargerFunctionSetting`.
+    ///
+    /// This flips whatever state the ISOFET is currently in. Since the
+    /// ISOFET gates the battery connection, prefer [`Self::disable_isofet`]
+    /// or [`Self::enable_isofet`] when the desired end state is known, since
+    /// those are idempotent and won't silently reconnect an already
+    /// disconnected battery.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn toggle_isofet(&mut self) -> Result<(), Error<I2C::Error>> {
+        let current = self.read_register(Register::ChargerFunctionSetting).await?;
+        let toggled = current ^ ChargerFunctionSetting::OFF_ISOFET.bits();
+        self.write_register(Register::ChargerFunctionSetting, toggled)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Disconnects the battery by setting the `OFF_ISOFET` bit.
+    ///
+    /// Unlike [`Self::toggle_isofet`], this is idempotent: calling it when
+    /// the ISOFET is already off leaves it off.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn disable_isofet(&mut self) -> Result<(), Error<I2C::Error>> {
+        let current = self.read_register(Register::ChargerFunctionSetting).await?;
+        let updated = current | ChargerFunctionSetting::OFF_ISOFET.bits();
+        self.write_register(Register::ChargerFunctionSetting, updated)
+            .await
+            .map_err(Error::I2C)
     }
 
-    /// Reads the battery voltage.
+    /// Reconnects the battery by clearing the `OFF_ISOFET` bit.
     ///
-    /// This function reads the battery voltage register which returns a 16-bit value
-    /// representing the current battery voltage.
+    /// Unlike [`Self::toggle_isofet`], this is idempotent: calling it when
+    /// the ISOFET is already on leaves it on.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an I2C bus error
+    pub async fn enable_isofet(&mut self) -> Result<(), Error<I2C::Error>> {
+        let current = self.read_register(Register::ChargerFunctionSetting).await?;
+        let updated = current & !ChargerFunctionSetting::OFF_ISOFET.bits();
+        self.write_register(Register::ChargerFunctionSetting, updated)
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Reads the manufacturer/model ID and silicon revision.
     ///
     /// # Returns
     ///
-    /// A Result containing the battery voltage as a 16-bit value or an I2C bus error
-    pub async fn read_battery_voltage(&mut self) -> Result<u8, I2C::Error> {
-        self.read_register(Register::BatterySoc).await
+    /// A Result containing the `(ManufacturerModel, Revision)` pair or an I2C bus error
+    pub async fn read_device_id(
+        &mut self,
+    ) -> Result<(ManufacturerModel, Revision), Error<I2C::Error>> {
+        let model = self.read_register(Register::ManufacturerModelId).await?;
+        let revision = self.read_register(Register::SiliconRevision).await?;
+        Ok((ManufacturerModel(model), Revision(revision)))
+    }
+
+    /// Verifies that the device on the bus is a genuine ADP5360.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::UnexpectedDevice` if `ManufacturerModelId` does not
+    /// match the expected value.
+    pub async fn probe(&mut self) -> Result<(), Error<I2C::Error>> {
+        let (model, _revision) = self.read_device_id().await?;
+        if model.0 != ADP5360_MANUFACTURER_MODEL_ID {
+            return Err(Error::UnexpectedDevice { id: model.0 });
+        }
+        Ok(())
+    }
+
+    /// Creates a new ADP5360 driver and verifies its identity on the bus.
+    ///
+    /// # Arguments
+    ///
+    /// * `i2c` - The I2C bus implementation
+    /// * `address` - The 7-bit I2C address of the device (typically 0x68)
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::UnexpectedDevice` if the device does not report the
+    /// expected ADP5360 manufacturer/model ID, or `Error::I2C` on a bus error.
+    pub async fn new_and_probe(i2c: I2C, address: u8) -> Result<Self, Error<I2C::Error>> {
+        let mut device = Self::new(i2c, address);
+        device.probe().await?;
+        Ok(device)
     }
 }
 
@@ -239,30 +857,461 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_read_battery_voltage() {
+    async fn test_read_register() {
         let expectations = [
-            I2cTransaction::write_read(0x68, vec![Register::BatterySoc as u8], vec![0x12]), // Read battery voltage register
+            I2cTransaction::write_read(0x68, vec![Register::ChargerStatus1 as u8], vec![0x55]), // Read from arbitrary register
         ];
         let mut i2c = I2cMock::new(&expectations);
 
         let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
-        let result = adp5360.read_battery_voltage().await;
+        let result = adp5360.read_register(Register::ChargerStatus1).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 0x12); // Check the combined bytes
+        assert_eq!(result.unwrap(), 0x55);
 
         i2c.done();
     }
+
     #[tokio::test]
-    async fn test_read_register() {
+    async fn test_configure_fuel_gauge() {
+        let model = BatteryModel {
+            voltage_soc_0: 0x10,
+            voltage_soc_5: 0x20,
+            voltage_soc_11: 0x30,
+            voltage_soc_19: 0x40,
+            voltage_soc_28: 0x50,
+            voltage_soc_41: 0x60,
+            voltage_soc_55: 0x70,
+            voltage_soc_69: 0x80,
+            voltage_soc_84: 0x90,
+            voltage_soc_100: 0xA0,
+            battery_capacity: 0x64,
+        };
         let expectations = [
-            I2cTransaction::write_read(0x68, vec![Register::ChargerStatus1 as u8], vec![0x55]), // Read from arbitrary register
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc0 as u8, 0x10]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc5 as u8, 0x20]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc11 as u8, 0x30]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc19 as u8, 0x40]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc28 as u8, 0x50]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc41 as u8, 0x60]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc55 as u8, 0x70]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc69 as u8, 0x80]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc84 as u8, 0x90]),
+            I2cTransaction::write(0x68, vec![Register::VoltageSoc100 as u8, 0xA0]),
+            I2cTransaction::write(0x68, vec![Register::BatteryCapacity as u8, 0x64]),
         ];
         let mut i2c = I2cMock::new(&expectations);
 
         let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
-        let result = adp5360.read_register(Register::ChargerStatus1).await;
+        assert!(adp5360.configure_fuel_gauge(&model).await.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_read_state_of_charge() {
+        let expectations = [I2cTransaction::write_read(
+            0x68,
+            vec![Register::BatterySoc as u8],
+            vec![0x32],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360.read_state_of_charge().await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 0x55);
+        assert_eq!(result.unwrap(), 0x32);
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_enable_fuel_gauge() {
+        let expectations = [I2cTransaction::write(
+            0x68,
+            vec![Register::BatterySocAccumulationControl as u8, 0x01],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360.enable_fuel_gauge().await.is_ok());
+
+        i2c.done();
+    }
+
+    #[test]
+    fn test_battery_model_is_monotonic() {
+        let model = BatteryModel {
+            voltage_soc_0: 0x10,
+            voltage_soc_5: 0x20,
+            voltage_soc_11: 0x30,
+            voltage_soc_19: 0x40,
+            voltage_soc_28: 0x50,
+            voltage_soc_41: 0x60,
+            voltage_soc_55: 0x70,
+            voltage_soc_69: 0x80,
+            voltage_soc_84: 0x90,
+            voltage_soc_100: 0xA0,
+            battery_capacity: 0x64,
+        };
+        assert!(model.is_monotonic());
+
+        let mut broken = model;
+        broken.voltage_soc_55 = 0x10;
+        assert!(!broken.is_monotonic());
+    }
+
+    #[tokio::test]
+    async fn test_configure_fuel_gauge_rejects_non_monotonic_model() {
+        let mut broken = BatteryModel {
+            voltage_soc_0: 0x10,
+            voltage_soc_5: 0x20,
+            voltage_soc_11: 0x30,
+            voltage_soc_19: 0x40,
+            voltage_soc_28: 0x50,
+            voltage_soc_41: 0x60,
+            voltage_soc_55: 0x70,
+            voltage_soc_69: 0x80,
+            voltage_soc_84: 0x90,
+            voltage_soc_100: 0xA0,
+            battery_capacity: 0x64,
+        };
+        broken.voltage_soc_55 = 0x10;
+
+        let mut i2c = I2cMock::new(&[]);
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360.configure_fuel_gauge(&broken).await;
+        assert_eq!(result, Err(Error::InvalidConfig));
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_read_device_id() {
+        let expectations = [
+            I2cTransaction::write_read(0x68, vec![Register::ManufacturerModelId as u8], vec![0x00]),
+            I2cTransaction::write_read(0x68, vec![Register::SiliconRevision as u8], vec![0x02]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let (model, revision) = adp5360.read_device_id().await.unwrap();
+        assert_eq!(model, ManufacturerModel(0x00));
+        assert_eq!(revision, Revision(0x02));
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_probe_rejects_unexpected_device() {
+        let expectations = [
+            I2cTransaction::write_read(0x68, vec![Register::ManufacturerModelId as u8], vec![0xFF]),
+            I2cTransaction::write_read(0x68, vec![Register::SiliconRevision as u8], vec![0x02]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360.probe().await;
+        assert_eq!(result, Err(Error::UnexpectedDevice { id: 0xFF }));
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_new_and_probe() {
+        let expectations = [
+            I2cTransaction::write_read(0x68, vec![Register::ManufacturerModelId as u8], vec![0x00]),
+            I2cTransaction::write_read(0x68, vec![Register::SiliconRevision as u8], vec![0x02]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        assert!(ADP5360::new_and_probe(i2c.clone(), 0x68).await.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_read_charger_status() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::ChargerStatus1 as u8],
+                vec![0b0001_1011],
+            ),
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::ChargerStatus2 as u8],
+                vec![0b0010_0001],
+            ),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let status = adp5360.read_charger_status().await.unwrap();
+
+        assert_eq!(status.state, ChargerState::FastChargeConstantVoltage);
+        assert!(status.vbus_present);
+        assert!(status.vbus_overvoltage);
+        assert_eq!(status.thermistor_status, ThermalRegion::Cool);
+        assert!(status.battery_overvoltage);
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_enable_interrupts() {
+        let expectations = [
+            I2cTransaction::write_read(0x68, vec![Register::InterruptEnable1 as u8], vec![0x01]),
+            I2cTransaction::write(0x68, vec![Register::InterruptEnable1 as u8, 0x21]),
+            I2cTransaction::write_read(0x68, vec![Register::InterruptEnable2 as u8], vec![0x00]),
+            I2cTransaction::write(0x68, vec![Register::InterruptEnable2 as u8, 0x01]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360
+            .enable_interrupts(Interrupts::CHARGE_COMPLETE | Interrupts::VBUS_CHANGE)
+            .await;
+        assert!(result.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_take_pending_interrupts() {
+        let expectations = [
+            I2cTransaction::write_read(0x68, vec![Register::InterruptFlag1 as u8], vec![0x20]),
+            I2cTransaction::write_read(0x68, vec![Register::InterruptFlag2 as u8], vec![0x01]),
+            I2cTransaction::write(0x68, vec![Register::InterruptFlag1 as u8, 0x20]),
+            I2cTransaction::write(0x68, vec![Register::InterruptFlag2 as u8, 0x01]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let pending = adp5360.take_pending_interrupts().await.unwrap();
+        assert_eq!(
+            pending,
+            Interrupts::CHARGE_COMPLETE | Interrupts::VBUS_CHANGE
+        );
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_configure_battery_protection() {
+        let protection = BatteryProtection {
+            control: BatteryProtectionControl::EN_UVLO | BatteryProtectionControl::EN_OV,
+            undervoltage_threshold: 0x01,
+            overcharge_threshold: 0x02,
+            overvoltage_threshold: 0x03,
+            charge_overcharge_threshold: 0x04,
+        };
+        let expectations = [
+            I2cTransaction::write(0x68, vec![Register::BatteryProtectionControl as u8, 0x05]),
+            I2cTransaction::write(
+                0x68,
+                vec![Register::BatteryProtectionUndervoltageSetting as u8, 0x01],
+            ),
+            I2cTransaction::write(
+                0x68,
+                vec![Register::BatteryProtectionOverchargeSetting as u8, 0x02],
+            ),
+            I2cTransaction::write(
+                0x68,
+                vec![Register::BatteryProtectionOvervoltageSetting as u8, 0x03],
+            ),
+            I2cTransaction::write(
+                0x68,
+                vec![
+                    Register::BatteryProtectionChargeOverchargeSetting as u8,
+                    0x04,
+                ],
+            ),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360
+            .configure_battery_protection(&protection)
+            .await
+            .is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_read_battery_protection() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::BatteryProtectionControl as u8],
+                vec![0x05],
+            ),
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::BatteryProtectionUndervoltageSetting as u8],
+                vec![0x01],
+            ),
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::BatteryProtectionOverchargeSetting as u8],
+                vec![0x02],
+            ),
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::BatteryProtectionOvervoltageSetting as u8],
+                vec![0x03],
+            ),
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::BatteryProtectionChargeOverchargeSetting as u8],
+                vec![0x04],
+            ),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let protection = adp5360.read_battery_protection().await.unwrap();
+
+        assert_eq!(
+            protection.control,
+            BatteryProtectionControl::EN_UVLO | BatteryProtectionControl::EN_OV
+        );
+        assert_eq!(protection.undervoltage_threshold, 0x01);
+        assert_eq!(protection.overcharge_threshold, 0x02);
+        assert_eq!(protection.overvoltage_threshold, 0x03);
+        assert_eq!(protection.charge_overcharge_threshold, 0x04);
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_configure_thermistor() {
+        let config = ThermistorConfig {
+            control: ThermistorControl::EN_THR | ThermistorControl::EN_IBAT,
+            threshold_0c: 0x11,
+            threshold_10c: 0x22,
+            threshold_45c: 0x33,
+            threshold_60c: 0x44,
+        };
+        let expectations = [
+            I2cTransaction::write(0x68, vec![Register::BatteryThermistorControl as u8, 0x03]),
+            I2cTransaction::write(0x68, vec![Register::Thermistor60CThreshold as u8, 0x44]),
+            I2cTransaction::write(0x68, vec![Register::Thermistor45CThreshold as u8, 0x33]),
+            I2cTransaction::write(0x68, vec![Register::Thermistor10CThreshold as u8, 0x22]),
+            I2cTransaction::write(0x68, vec![Register::Thermistor0CThreshold as u8, 0x11]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360.configure_thermistor(config).await.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_read_battery_temperature_region() {
+        let expectations = [I2cTransaction::write_read(
+            0x68,
+            vec![Register::ChargerStatus2 as u8],
+            vec![0b0100_0000],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let region = adp5360.read_battery_temperature_region().await.unwrap();
+        assert_eq!(region, ThermalRegion::Warm);
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_enter_ship_mode() {
+        let expectations = [I2cTransaction::write(
+            0x68,
+            vec![Register::ShipMode as u8, 0x01],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360
+            .enter_ship_mode(ShipModeConfig {
+                wake_source: WakeSource::VbusInsertion,
+            })
+            .await;
+        assert!(result.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_enter_ship_mode_wake_on_button() {
+        let expectations = [I2cTransaction::write(
+            0x68,
+            vec![Register::ShipMode as u8, 0x03],
+        )];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        let result = adp5360
+            .enter_ship_mode(ShipModeConfig {
+                wake_source: WakeSource::Button,
+            })
+            .await;
+        assert!(result.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_toggle_isofet() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::ChargerFunctionSetting as u8],
+                vec![0x01],
+            ),
+            I2cTransaction::write(0x68, vec![Register::ChargerFunctionSetting as u8, 0x11]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360.toggle_isofet().await.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_disable_isofet_is_idempotent() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::ChargerFunctionSetting as u8],
+                vec![0x11],
+            ),
+            I2cTransaction::write(0x68, vec![Register::ChargerFunctionSetting as u8, 0x11]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360.disable_isofet().await.is_ok());
+
+        i2c.done();
+    }
+
+    #[tokio::test]
+    async fn test_enable_isofet_is_idempotent() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x68,
+                vec![Register::ChargerFunctionSetting as u8],
+                vec![0x01],
+            ),
+            I2cTransaction::write(0x68, vec![Register::ChargerFunctionSetting as u8, 0x01]),
+        ];
+        let mut i2c = I2cMock::new(&expectations);
+
+        let mut adp5360 = ADP5360::new(i2c.clone(), 0x68);
+        assert!(adp5360.enable_isofet().await.is_ok());
 
         i2c.done();
     }